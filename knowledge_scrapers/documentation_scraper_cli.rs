@@ -0,0 +1,97 @@
+// Thin command-line front end over the `documentation_scraper` library
+use documentation_scraper::{DiffOptions, DocumentationScraperRust, AUTO_PLATFORM};
+use std::path::PathBuf;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Progress at info level by default; override with RUST_LOG (e.g. RUST_LOG=documentation_scraper=debug)
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+
+    let args: Vec<String> = std::env::args().collect();
+
+    // `diff <platform> <previous_results.json>`: re-scrape a saved run and print what changed
+    if args.len() == 4 && args[1] == "diff" {
+        let scraper = DocumentationScraperRust::new(args[2].clone(), 1.0, 10);
+        let report = scraper.rescrape_and_diff(&args[3], &DiffOptions::default()).await?;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    // `merge <output.json> <results.json>...`: combine saved crawls into one corpus
+    if args.len() >= 4 && args[1] == "merge" {
+        let scraper = DocumentationScraperRust::new("generic".to_string(), 1.0, 10);
+        let inputs: Vec<PathBuf> = args[3..].iter().map(PathBuf::from).collect();
+        let merged = scraper.merge_result_files(&inputs, Some(args[2].clone())).await?;
+        println!("\n🧩 Merged {} result files into {} pages", inputs.len(), merged.len());
+        return Ok(());
+    }
+
+    // `feed <feed_url>`: save a changelog's RSS/Atom entries as dated pages
+    if args.len() == 3 && args[1] == "feed" {
+        let scraper = DocumentationScraperRust::new("generic".to_string(), 1.0, 10);
+        let pages = scraper.scrape_feed(&args[2]).await?;
+        scraper.save_results(&pages, None).await?;
+        println!("\n📰 Feed entries saved: {}", pages.len());
+        return Ok(());
+    }
+    
+    if args.len() < 4 {
+        println!("Usage: {} <platform> <base_url> <max_pages> [max_depth]", args[0]);
+        println!("       {} diff <platform> <previous_results.json>", args[0]);
+        println!("       {} feed <feed_url>", args[0]);
+        println!("       {} merge <output.json> <results.json>...", args[0]);
+        println!("Example: {} readthedocs https://docs.python.org/ 20", args[0]);
+        println!("Pass \"{}\" as the platform to detect it from the site", AUTO_PLATFORM);
+        std::process::exit(1);
+    }
+
+    let platform = args[1].clone();
+    let base_url = args[2].clone();
+    let max_pages: usize = args[3].parse().unwrap_or(20);
+    let max_depth: usize = args.get(4).and_then(|d| d.parse().ok()).unwrap_or(1);
+
+    // Create scraper with high concurrency for performance
+    let scraper = DocumentationScraperRust::new(platform, 1.0, 10).with_max_depth(max_depth);
+
+    // Scrape documentation site
+    let crawl = scraper.crawl(base_url, max_pages).await;
+    let pages = crawl.pages;
+
+    if !pages.is_empty() {
+        // Save results
+        scraper.save_results(&pages, None).await?;
+        
+        println!("\n✅ Documentation scraping completed successfully!");
+        println!("📊 Pages scraped: {}", pages.len());
+        
+        let total_code_examples: usize = pages.iter().map(|p| p.code_examples.len()).sum();
+        let total_api_endpoints: usize = pages.iter().map(|p| p.api_endpoints.len()).sum();
+        
+        println!("💻 Code examples found: {}", total_code_examples);
+        println!("🔗 API endpoints found: {}", total_api_endpoints);
+
+        let stats = scraper.stats();
+        let http_failures: usize = stats.http_failures.values().sum();
+        if http_failures + stats.network_errors > 0 || stats.retries > 0 {
+            println!(
+                "🩺 HTTP failures: {}, network errors: {}, retries: {}",
+                http_failures, stats.network_errors, stats.retries
+            );
+        }
+
+        if crawl.budget_exceeded {
+            println!("⛔ Stopped early: the crawl's byte or time budget ran out");
+        }
+        if !crawl.unscraped_urls.is_empty() {
+            println!("⏭️ Discovered but not scraped: {} (raise max_pages to include them)", crawl.unscraped_urls.len());
+        }
+    } else {
+        println!("⚠️ No pages were successfully scraped");
+    }
+
+    Ok(())
+}